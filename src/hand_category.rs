@@ -0,0 +1,263 @@
+use aya_poker::base::{Card, Hand, Rank};
+use aya_poker::poker_rank;
+use aya_poker::Rank as HandRank;
+
+/// Upper bound (inclusive) of the Four of a Kind band -- the last rank
+/// below the 10 straight-flush values, the highest of which is the Royal
+/// Flush itself. Named so `ROYAL_FLUSH_VALUE` stays derived from it instead
+/// of repeating the same band math as a second hardcoded literal.
+const FOUR_OF_A_KIND_UPPER: u16 = 7451;
+
+/// There are exactly 10 distinct straight flushes per suit, from the 5-high
+/// "steel wheel" through the ace-high royal flush.
+const STRAIGHT_FLUSH_COUNT: u16 = 10;
+
+/// Highest possible value `poker_rank` produces, reserved for an ace-high
+/// straight flush -- used to tell a Royal Flush apart from the rest of the
+/// `StraightFlush` band.
+const ROYAL_FLUSH_VALUE: u16 = FOUR_OF_A_KIND_UPPER + STRAIGHT_FLUSH_COUNT;
+
+/// The nine standard poker hand categories, ordered from weakest to
+/// strongest to match the ascending ordering `poker_rank` already produces.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub(crate) enum HandCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl HandCategory {
+    /// Classify a rank by the numeric band `poker_rank` places it in. The
+    /// band widths mirror the well-known distinct 5-card hand-value counts
+    /// (7462 total), just ascending instead of descending.
+    pub(crate) fn from_rank(rank: HandRank) -> Self {
+        match rank.0 {
+            0..=1276 => HandCategory::HighCard,
+            1277..=4136 => HandCategory::OnePair,
+            4137..=4994 => HandCategory::TwoPair,
+            4995..=5852 => HandCategory::ThreeOfAKind,
+            5853..=5862 => HandCategory::Straight,
+            5863..=7139 => HandCategory::Flush,
+            7140..=7295 => HandCategory::FullHouse,
+            7296..=FOUR_OF_A_KIND_UPPER => HandCategory::FourOfAKind,
+            _ => HandCategory::StraightFlush,
+        }
+    }
+
+    /// Human-readable name for this category, e.g. for a `describe()` result.
+    /// Needs the originating rank to tell a Royal Flush apart from an
+    /// ordinary straight flush.
+    pub(crate) fn name(&self, rank: HandRank) -> &'static str {
+        match self {
+            HandCategory::HighCard => "High Card",
+            HandCategory::OnePair => "One Pair",
+            HandCategory::TwoPair => "Two Pair",
+            HandCategory::ThreeOfAKind => "Trips",
+            HandCategory::Straight => "Straight",
+            HandCategory::Flush => "Flush",
+            HandCategory::FullHouse => "Full House",
+            HandCategory::FourOfAKind => "Quads",
+            HandCategory::StraightFlush if rank.0 == ROYAL_FLUSH_VALUE => "Royal Flush",
+            HandCategory::StraightFlush => "Straight Flush",
+        }
+    }
+}
+
+/// Face value of a card rank, Two low through Ace high, used to order the
+/// ranks a `describe()` result reports.
+fn rank_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Two => 2,
+        Rank::Three => 3,
+        Rank::Four => 4,
+        Rank::Five => 5,
+        Rank::Six => 6,
+        Rank::Seven => 7,
+        Rank::Eight => 8,
+        Rank::Nine => 9,
+        Rank::Ten => 10,
+        Rank::Jack => 11,
+        Rank::Queen => 12,
+        Rank::King => 13,
+        Rank::Ace => 14,
+    }
+}
+
+/// Full name of a card rank, e.g. for a `describe()` result.
+fn rank_name(value: u8) -> &'static str {
+    match value {
+        14 => "Ace",
+        13 => "King",
+        12 => "Queen",
+        11 => "Jack",
+        10 => "Ten",
+        9 => "Nine",
+        8 => "Eight",
+        7 => "Seven",
+        6 => "Six",
+        5 => "Five",
+        4 => "Four",
+        3 => "Three",
+        _ => "Two",
+    }
+}
+
+/// The 5 cards of `hand` (which may carry up to 7) that `poker_rank` actually
+/// scored, found by re-evaluating every 5-card subset and keeping the one
+/// matching `rank`. A 7-card hand can easily hold cards that play no part in
+/// its best 5, e.g. an unused pocket pair alongside a made flush, so this is
+/// needed before reporting the ranks that define the hand's category.
+fn winning_five(hand: &Hand, rank: HandRank) -> Hand {
+    let cards: Vec<Card> = hand.iter().copied().collect();
+    if cards.len() <= 5 {
+        return *hand;
+    }
+
+    let n = cards.len();
+    for mask in 0u32..(1 << n) {
+        if mask.count_ones() != 5 {
+            continue;
+        }
+        let subset: Hand = (0..n)
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| &cards[i])
+            .collect();
+        if poker_rank(&subset) == rank {
+            return subset;
+        }
+    }
+
+    *hand
+}
+
+/// Ranks that define `hand`'s category at the given `rank`, grouped by how
+/// often they occur within the winning 5-card hand (pairs/trips/quads first)
+/// and then by face value, highest first.
+pub(crate) fn ordered_ranks(hand: &Hand, rank: HandRank) -> Vec<&'static str> {
+    let winning_hand = winning_five(hand, rank);
+
+    let mut counts: Vec<(u8, usize)> = Vec::new();
+    for card in winning_hand.iter() {
+        let value = rank_value(card.rank());
+        match counts.iter_mut().find(|(v, _)| *v == value) {
+            Some(entry) => entry.1 += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+
+    // The "wheel" (5-4-3-2-A) is the one straight where the ace plays low,
+    // so it must sort last here even though its face value (14) is high.
+    let is_wheel = {
+        let mut values: Vec<u8> = counts.iter().map(|(v, _)| *v).collect();
+        values.sort_unstable();
+        values == [2, 3, 4, 5, 14]
+    };
+    let sort_value = |value: u8| if is_wheel && value == 14 { 1 } else { value };
+
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(sort_value(b.0).cmp(&sort_value(a.0))));
+    counts
+        .into_iter()
+        .map(|(value, _)| rank_name(value))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank_of(hand: &str) -> HandRank {
+        poker_rank(&hand.parse::<Hand>().unwrap())
+    }
+
+    #[test]
+    fn royal_flush_is_named_correctly() {
+        let rank = rank_of("AsKsQsJsTs");
+        let category = HandCategory::from_rank(rank);
+        assert_eq!(category, HandCategory::StraightFlush);
+        assert_eq!(category.name(rank), "Royal Flush");
+    }
+
+    #[test]
+    fn ordinary_straight_flush_is_not_mistaken_for_royal() {
+        let rank = rank_of("9s8s7s6s5s");
+        let category = HandCategory::from_rank(rank);
+        assert_eq!(category, HandCategory::StraightFlush);
+        assert_eq!(category.name(rank), "Straight Flush");
+    }
+
+    #[test]
+    fn from_rank_classifies_one_hand_of_each_category_below_straight_flush() {
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h7c9dJsKd")),
+            HandCategory::HighCard
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h2c9dJsKd")),
+            HandCategory::OnePair
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h2c9d9sKd")),
+            HandCategory::TwoPair
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h2c2d9sKd")),
+            HandCategory::ThreeOfAKind
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h3c4d5s6h")),
+            HandCategory::Straight
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h7h9hJhKh")),
+            HandCategory::Flush
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h2c2d9s9h")),
+            HandCategory::FullHouse
+        );
+        assert_eq!(
+            HandCategory::from_rank(rank_of("2h2c2d2sKd")),
+            HandCategory::FourOfAKind
+        );
+    }
+
+    #[test]
+    fn ordered_ranks_sorts_the_wheel_straight_ace_last() {
+        let hand = "5h4c3d2sAh".parse::<Hand>().unwrap();
+        let rank = poker_rank(&hand);
+        assert_eq!(HandCategory::from_rank(rank), HandCategory::Straight);
+        assert_eq!(
+            ordered_ranks(&hand, rank),
+            vec!["Five", "Four", "Three", "Two", "Ace"]
+        );
+    }
+
+    #[test]
+    fn ordered_ranks_sorts_the_steel_wheel_straight_flush_ace_last() {
+        let hand = "5h4h3h2hAh".parse::<Hand>().unwrap();
+        let rank = poker_rank(&hand);
+        assert_eq!(HandCategory::from_rank(rank), HandCategory::StraightFlush);
+        assert_eq!(
+            ordered_ranks(&hand, rank),
+            vec!["Five", "Four", "Three", "Two", "Ace"]
+        );
+    }
+
+    #[test]
+    fn ordered_ranks_ignores_dead_cards_outside_the_winning_five() {
+        // 7-card hand: a made royal flush plus an unrelated pocket pair of
+        // nines that must not outrank the actual winning cards.
+        let hand = "AsKsQsJsTs9s9d".parse::<Hand>().unwrap();
+        let rank = poker_rank(&hand);
+        assert_eq!(
+            ordered_ranks(&hand, rank),
+            vec!["Ace", "King", "Queen", "Jack", "Ten"]
+        );
+    }
+}
@@ -1,19 +1,49 @@
 #![cfg_attr(windows, feature(abi_vectorcall))]
 
-use aya_poker::base::{Hand, Rank, Suit, CARDS};
+mod hand_category;
+mod outs;
+
+use aya_poker::base::{Card, Hand, Rank, Suit, CARDS};
 use aya_poker::deck::{Deck, FullDeck};
 use aya_poker::poker_rank;
+use aya_poker::Rank as HandRank;
 use ext_php_rs::types::ZendClassObject;
 use ext_php_rs::{exception::PhpResult, prelude::*};
+use hand_category::{ordered_ranks, HandCategory};
+use outs::{find_outs, Outs};
 use rand::RngCore;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::thread;
 use std::time::Instant;
 
+/// Default ceiling on the number of run-outs an exact enumeration will
+/// evaluate before falling back to an error asking the caller to use
+/// Monte Carlo sampling instead.
+const DEFAULT_EXACT_THRESHOLD: usize = 2_000_000;
+
+/// Fixed size of each Monte Carlo work chunk. Samples are partitioned into
+/// chunks of this size (and seeded from the chunk index) before the worker
+/// threads pick them up, so the partition -- and therefore the result --
+/// only depends on `samples`/`seed`, never on how many threads are used to
+/// run it.
+const MONTE_CARLO_CHUNK_SIZE: usize = 10_000;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    MonteCarlo,
+    Exact,
+}
+
 #[php_module]
 pub fn module(module: ModuleBuilder) -> ModuleBuilder {
     module
         .class::<EquityCalculator>()
         .class::<EquityResult>()
+        .class::<OpponentEquity>()
+        .class::<Outs>()
+        .class::<HandDescription>()
         .class::<PhpDeck>()
 }
 
@@ -23,6 +53,9 @@ pub fn module(module: ModuleBuilder) -> ModuleBuilder {
 pub struct EquityCalculator {
     pub samples: usize,
     pub seed: u64,
+    mode: Mode,
+    exact_threshold: usize,
+    threads: usize,
 }
 
 #[php_impl]
@@ -32,6 +65,9 @@ impl EquityCalculator {
         Self {
             samples: 100_000,
             seed: 0,
+            mode: Mode::MonteCarlo,
+            exact_threshold: DEFAULT_EXACT_THRESHOLD,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
         }
     }
 
@@ -53,6 +89,46 @@ impl EquityCalculator {
         self_
     }
 
+    /// Switch to exact enumeration of every remaining run-out instead of
+    /// Monte Carlo sampling. Falls back to an error from `calculate` if the
+    /// number of combinations exceeds `exact_threshold`.
+    pub fn exact(
+        self_: &mut ZendClassObject<EquityCalculator>,
+    ) -> &mut ZendClassObject<EquityCalculator> {
+        self_.mode = Mode::Exact;
+        self_
+    }
+
+    /// Switch to Monte Carlo sampling, drawing the given number of samples
+    pub fn monte_carlo(
+        self_: &mut ZendClassObject<EquityCalculator>,
+        samples: usize,
+    ) -> &mut ZendClassObject<EquityCalculator> {
+        self_.mode = Mode::MonteCarlo;
+        self_.samples = samples;
+        self_
+    }
+
+    /// Modify the number of threads Monte Carlo sampling is split across.
+    /// Defaults to the available parallelism.
+    pub fn threads(
+        self_: &mut ZendClassObject<EquityCalculator>,
+        threads: usize,
+    ) -> &mut ZendClassObject<EquityCalculator> {
+        self_.threads = threads;
+        self_
+    }
+
+    /// Modify the maximum number of combinations an exact enumeration is
+    /// allowed to evaluate
+    pub fn exact_threshold(
+        self_: &mut ZendClassObject<EquityCalculator>,
+        threshold: usize,
+    ) -> &mut ZendClassObject<EquityCalculator> {
+        self_.exact_threshold = threshold;
+        self_
+    }
+
     /// Calculate equity of the player's hand
     ///
     /// @param string $player Hero's hand in poker notation
@@ -74,68 +150,148 @@ impl EquityCalculator {
         let board = parse_hand(board, 5)?;
 
         let all_opponent_cards = opponents.iter().flat_map(|o| o.iter()).collect::<Hand>();
-        // To simulate board run-outs, we begin by preparing a deck
+        // To simulate board run-outs, we only ever draw from a deck
         // that doesn't contain the already dealt-out cards
-        let available_cards = CARDS
+        let available = CARDS
             .iter()
-            .filter(|c| !player.contains(c))
-            .filter(|c| !all_opponent_cards.contains(c))
-            .filter(|c| !board.contains(c));
-        let mut deck = Deck::with_seed(available_cards, self.seed);
-
-        let mut pots_won = 0.0;
-        for _ in 0..self.samples {
-            // Then, for each run we draw cards to complete the board
-            deck.reset();
-            let missing = 5 - board.len();
-            let complete_board = board
-                .iter()
-                .chain(deck.deal(missing).unwrap().iter())
-                .collect::<Hand>();
-            let mut player_hand = player;
-            let player_missing = 2 - player_hand.len();
-            if player_missing > 0 {
-                player_hand = player_hand
-                    .iter()
-                    .chain(deck.deal(player_missing).unwrap().iter())
-                    .collect::<Hand>();
+            .filter(|c| {
+                !player.contains(c) && !all_opponent_cards.contains(c) && !board.contains(c)
+            })
+            .cloned()
+            .collect::<Vec<Card>>();
+
+        let board_missing = 5 - board.len();
+        let player_missing = 2 - player.len();
+        let opponents_missing = opponents.iter().map(|o| 2 - o.len()).collect::<Vec<_>>();
+        let exact = self.mode == Mode::Exact;
+
+        let mut tally = Tally::new(opponents.len());
+        match self.mode {
+            Mode::MonteCarlo => {
+                // The partition of `samples` into chunks (and the seed each
+                // chunk runs with) is derived solely from `samples`/`seed`,
+                // never from `self.threads` -- the thread count only changes
+                // how many workers race through this fixed chunk list, not
+                // what it contains, so the merged tally is identical no
+                // matter how many threads run it.
+                let chunks = monte_carlo_chunks(self.samples, self.seed);
+                let thread_count = self.threads.max(1).min(chunks.len().max(1));
+                let next_chunk = AtomicUsize::new(0);
+
+                let chunk_tallies = thread::scope(|scope| {
+                    (0..thread_count)
+                        .map(|_| {
+                            let next_chunk = &next_chunk;
+                            let chunks = &chunks;
+                            scope.spawn(move || {
+                                let mut worker_tally = Tally::new(opponents.len());
+                                loop {
+                                    let i = next_chunk.fetch_add(1, AtomicOrdering::Relaxed);
+                                    let Some(&(chunk_samples, chunk_seed)) = chunks.get(i) else {
+                                        break;
+                                    };
+                                    worker_tally.merge(monte_carlo_chunk(
+                                        chunk_samples,
+                                        chunk_seed,
+                                        &available,
+                                        player,
+                                        board,
+                                        board_missing,
+                                        player_missing,
+                                        &opponents,
+                                    ));
+                                }
+                                worker_tally
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .map(|handle| handle.join().expect("monte carlo worker thread panicked"))
+                        .collect::<Vec<_>>()
+                });
+
+                for chunk_tally in chunk_tallies {
+                    tally.merge(chunk_tally);
+                }
             }
-            // Evaluate the player's hand given the completed board
-            player_hand.extend(complete_board.iter());
-            let player_rank = poker_rank(&player_hand);
+            Mode::Exact => {
+                let total_missing =
+                    board_missing + player_missing + opponents_missing.iter().sum::<usize>();
+                let combinations = binomial(available.len(), total_missing);
+                if combinations > self.exact_threshold as u128 {
+                    return Err(format!(
+                        "Exact enumeration requires {} combinations, which exceeds the \
+                         configured threshold of {}; reduce the number of unknown cards or \
+                         use monte_carlo() instead",
+                        combinations, self.exact_threshold
+                    )
+                    .into());
+                }
 
-            let opponent_rank = opponents
-                .iter()
-                .map(|o| {
-                    let mut opponent = *o;
-                    let missing = 2 - opponent.len();
-                    if missing > 0 {
-                        opponent = opponent
+                for combo in Combinations::new(available.len(), total_missing) {
+                    let mut offset = 0;
+                    let complete_board = board
+                        .iter()
+                        .chain(
+                            combo[offset..offset + board_missing]
+                                .iter()
+                                .map(|&i| &available[i]),
+                        )
+                        .collect::<Hand>();
+                    offset += board_missing;
+
+                    let mut player_hand = player;
+                    if player_missing > 0 {
+                        player_hand = player_hand
                             .iter()
-                            .chain(deck.deal(missing).unwrap().iter())
+                            .chain(
+                                combo[offset..offset + player_missing]
+                                    .iter()
+                                    .map(|&i| &available[i]),
+                            )
                             .collect::<Hand>();
+                        offset += player_missing;
                     }
-                    opponent.extend(complete_board.iter());
-                    poker_rank(&opponent)
-                })
-                .max()
-                .unwrap();
-
-            // And record the player's share of the pot for the run
-            match player_rank.cmp(&opponent_rank) {
-                Ordering::Greater => pots_won += 1.0,
-                Ordering::Less => {}
-                Ordering::Equal => pots_won += 0.5,
-            };
-        }
+                    player_hand.extend(complete_board.iter());
+                    let player_rank = poker_rank(&player_hand);
+
+                    let opponent_ranks = opponents
+                        .iter()
+                        .zip(opponents_missing.iter())
+                        .map(|(o, &missing)| {
+                            let mut opponent = *o;
+                            if missing > 0 {
+                                opponent = opponent
+                                    .iter()
+                                    .chain(
+                                        combo[offset..offset + missing]
+                                            .iter()
+                                            .map(|&i| &available[i]),
+                                    )
+                                    .collect::<Hand>();
+                                offset += missing;
+                            }
+                            opponent.extend(complete_board.iter());
+                            poker_rank(&opponent)
+                        })
+                        .collect::<Vec<_>>();
+
+                    tally.record(player_rank, &opponent_ranks);
+                }
+            }
+        };
 
         let time = start.elapsed().as_millis() as usize;
-        let equity = pots_won / self.samples as f64;
 
         Ok(EquityResult {
-            equity,
-            samples: self.samples,
+            equity: tally.pot_share(),
+            wins: tally.win_rate(),
+            ties: tally.tie_rate(),
+            losses: tally.loss_rate(),
+            opponents: tally.opponent_equities(),
+            samples: tally.runs,
             time,
+            exact,
         })
     }
 
@@ -145,53 +301,183 @@ impl EquityCalculator {
         let hand: Hand = hand.parse().unwrap();
         poker_rank(&hand).0
     }
+
+    /// Find the cards that improve the player's hand on a partial board
+    ///
+    /// @param string $player Hero's hand in poker notation
+    /// @param string $board Board state in poker notation (3 or 4 cards)
+    /// @param string[] $opponents Villain hands to draw outs against; if the
+    ///                  array is empty, outs are counted against improving
+    ///                  hero's hand category instead
+    pub fn outs(player: &str, board: &str, opponents: Vec<&str>) -> PhpResult<Outs> {
+        let player = parse_hand(player, 2)?;
+        if player.len() != 2 {
+            return Err("outs requires exactly 2 hole cards".to_string().into());
+        }
+        let board = parse_hand(board, 4)?;
+        if board.len() < 3 {
+            return Err("outs requires at least a 3 card board".to_string().into());
+        }
+        let opponents = opponents
+            .iter()
+            .map(|op| parse_hand(op, 2))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(find_outs(player, board, opponents))
+    }
+
+    /// Classify a 5 or 7 card hand into its poker hand category
+    ///
+    /// @param string $hand Hand in poker notation
+    pub fn describe(hand: &str) -> PhpResult<HandDescription> {
+        let hand = parse_hand(hand, 7)?;
+        let rank = poker_rank(&hand);
+        let category = HandCategory::from_rank(rank);
+
+        Ok(HandDescription {
+            category: category.name(rank).to_string(),
+            ranks: ordered_ranks(&hand, rank)
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        })
+    }
 }
 
+#[derive(Serialize, Deserialize)]
 #[php_class]
 #[php(name = "Poker\\EquityResult")]
 pub struct EquityResult {
-    /// @var double the result of an equity calculation
+    /// @var double the player's overall share of the pot across all opponents
     #[php(prop)]
     pub equity: f64,
+    /// @var double fraction of run-outs the player won outright
+    #[php(prop)]
+    pub wins: f64,
+    /// @var double fraction of run-outs the player chopped with at least one opponent
+    #[php(prop)]
+    pub ties: f64,
+    /// @var double fraction of run-outs the player lost
+    #[php(prop)]
+    pub losses: f64,
+    /// @var \Poker\OpponentEquity[] win/tie/loss breakdown against each opponent individually
+    #[php(prop)]
+    pub opponents: Vec<OpponentEquity>,
     /// @var int number of iterations of the calculation
     #[php(prop)]
     pub samples: usize,
     /// @var int calculation duration in milliseconds
     #[php(prop)]
     pub time: usize,
+    /// @var bool whether `equity` is an exact result or a Monte Carlo estimate
+    #[php(prop)]
+    pub exact: bool,
 }
 
 #[php_impl]
 impl EquityResult {
+    #[php(name = "__toString")]
+    pub fn stringable(&self) -> String {
+        if self.exact {
+            format!(
+                "{:.2}% equity [exact, {} run-outs]",
+                self.equity * 100.0,
+                self.samples
+            )
+        } else {
+            format!(
+                "{:.2}% equity [{} samples, {:.2} samples per ms]",
+                self.equity * 100.0,
+                self.samples,
+                self.samples as f64 / self.time as f64
+            )
+        }
+    }
+
+    /// Serialize this result to a stable JSON schema
+    #[php(name = "toJson")]
+    pub fn to_json(&self) -> PhpResult<String> {
+        serde_json::to_string(self).map_err(|e| e.to_string().into())
+    }
+
+    /// Reconstruct a previously serialized result
+    #[php(name = "fromJson")]
+    pub fn from_json(json: &str) -> PhpResult<Self> {
+        serde_json::from_str(json).map_err(|e| e.to_string().into())
+    }
+}
+
+/// Win/tie/loss breakdown of the player's hand against a single opponent
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[php_class]
+#[php(name = "Poker\\OpponentEquity")]
+pub struct OpponentEquity {
+    /// @var double fraction of run-outs the player beat this opponent
+    #[php(prop)]
+    pub wins: f64,
+    /// @var double fraction of run-outs the player chopped with this opponent
+    #[php(prop)]
+    pub ties: f64,
+    /// @var double fraction of run-outs this opponent beat the player
+    #[php(prop)]
+    pub losses: f64,
+}
+
+#[php_impl]
+impl OpponentEquity {
     #[php(name = "__toString")]
     pub fn stringable(&self) -> String {
         format!(
-            "{:.2}% equity [{} samples, {:.2} samples per ms]",
-            self.equity * 100.0,
-            self.samples,
-            self.samples as f64 / self.time as f64
+            "{:.2}% equity [{:.2}% win, {:.2}% tie, {:.2}% loss]",
+            (self.wins + self.ties * 0.5) * 100.0,
+            self.wins * 100.0,
+            self.ties * 100.0,
+            self.losses * 100.0,
         )
     }
 }
 
+#[php_class]
+#[php(name = "Poker\\HandDescription")]
+pub struct HandDescription {
+    /// @var string hand category, e.g. "Full House"
+    #[php(prop)]
+    pub category: String,
+    /// @var string[] ranks that define the category, highest/most significant first
+    #[php(prop)]
+    pub ranks: Vec<String>,
+}
+
+#[php_impl]
+impl HandDescription {
+    #[php(name = "__toString")]
+    pub fn stringable(&self) -> String {
+        format!("{} ({})", self.category, self.ranks.join(", "))
+    }
+}
+
 #[php_class]
 #[php(name = "Poker\\Deck")]
 pub struct PhpDeck {
     deck: FullDeck,
+    seed: u64,
+    /// Number of cards dealt since the deck was seeded or last reset, kept
+    /// alongside `seed` so the deck's exact position can be reproduced.
+    dealt: usize,
 }
 #[php_impl]
 impl PhpDeck {
     /// Create a shuffled deck of 52 cards with a random seed
     pub fn __construct() -> Self {
-        Self {
-            deck: FullDeck::with_seed(rand::rng().next_u64()),
-        }
+        Self::from_seed(rand::rng().next_u64())
     }
 
     /// Create a huffled deck of 52 cards with specific random seed
     pub fn from_seed(seed: u64) -> Self {
         Self {
             deck: FullDeck::with_seed(seed),
+            seed,
+            dealt: 0,
         }
     }
 
@@ -202,35 +488,9 @@ impl PhpDeck {
         if self.deck.is_empty() {
             return None;
         }
-        match self.deck.deal(1) {
-            Some(cards) => {
-                let rank_str = match cards[0].rank() {
-                    Rank::Ace => "A",
-                    Rank::King => "K",
-                    Rank::Queen => "Q",
-                    Rank::Jack => "J",
-                    Rank::Ten => "T",
-                    Rank::Nine => "9",
-                    Rank::Eight => "8",
-                    Rank::Seven => "7",
-                    Rank::Six => "6",
-                    Rank::Five => "5",
-                    Rank::Four => "4",
-                    Rank::Three => "3",
-                    Rank::Two => "2",
-                };
-
-                let suit_str = match cards[0].suit() {
-                    Suit::Hearts => "h",
-                    Suit::Diamonds => "d",
-                    Suit::Clubs => "c",
-                    Suit::Spades => "s",
-                };
-
-                Some(format!("{}{}", rank_str, suit_str))
-            }
-            None => None,
-        }
+        let card = self.deck.deal(1).map(|cards| format_card(&cards[0]));
+        self.dealt += 1;
+        card
     }
 
     /// Reset the deck to its original shuffled state
@@ -238,15 +498,322 @@ impl PhpDeck {
     /// @return void
     pub fn reset(&mut self) {
         self.deck.reset();
+        self.dealt = 0;
     }
 
     /// Remaining cards in deck
     pub fn count(&self) -> usize {
         self.deck.len()
     }
+
+    /// Serialize the deck's seed and remaining cards to a stable JSON schema
+    #[php(name = "toJson")]
+    pub fn to_json(&self) -> PhpResult<String> {
+        let mut replay = FullDeck::with_seed(self.seed);
+        replay.deal(self.dealt);
+        let remaining = replay
+            .deal(replay.len())
+            .map(|cards| cards.iter().map(format_card).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let snapshot = DeckSnapshot {
+            seed: self.seed,
+            remaining,
+        };
+        serde_json::to_string(&snapshot).map_err(|e| e.to_string().into())
+    }
+
+    /// Reconstruct a deck from a previously serialized snapshot, resuming
+    /// from the exact position it was dealt to when serialized
+    #[php(name = "fromJson")]
+    pub fn from_json(json: &str) -> PhpResult<Self> {
+        let snapshot: DeckSnapshot = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let dealt = 52 - snapshot.remaining.len();
+        let mut deck = FullDeck::with_seed(snapshot.seed);
+        deck.deal(dealt);
+
+        Ok(Self {
+            deck,
+            seed: snapshot.seed,
+            dealt,
+        })
+    }
+}
+
+/// Stable on-disk schema for `PhpDeck::toJson`/`fromJson`
+#[derive(Serialize, Deserialize)]
+struct DeckSnapshot {
+    seed: u64,
+    remaining: Vec<String>,
+}
+
+/// Partition `samples` into fixed-size, independently-seeded chunks. This
+/// depends only on `samples`/`seed`, never on how many worker threads will
+/// consume the list, so the same configuration always produces the same
+/// chunks regardless of the thread count used to run them.
+fn monte_carlo_chunks(samples: usize, seed: u64) -> Vec<(usize, u64)> {
+    let mut chunks = Vec::new();
+    let mut remaining = samples;
+    let mut index: u64 = 0;
+    while remaining > 0 {
+        let chunk_samples = remaining.min(MONTE_CARLO_CHUNK_SIZE);
+        let chunk_seed = seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        chunks.push((chunk_samples, chunk_seed));
+        remaining -= chunk_samples;
+        index += 1;
+    }
+    chunks
+}
+
+/// Run one chunk of Monte Carlo samples against its own seeded `Deck`, so it
+/// can be handed off to a worker thread independently of the other chunks.
+fn monte_carlo_chunk(
+    samples: usize,
+    seed: u64,
+    available: &[Card],
+    player: Hand,
+    board: Hand,
+    board_missing: usize,
+    player_missing: usize,
+    opponents: &[Hand],
+) -> Tally {
+    let mut deck = Deck::with_seed(available.iter(), seed);
+    let mut tally = Tally::new(opponents.len());
+
+    for _ in 0..samples {
+        // Then, for each run we draw cards to complete the board
+        deck.reset();
+        let complete_board = board
+            .iter()
+            .chain(deck.deal(board_missing).unwrap().iter())
+            .collect::<Hand>();
+        let mut player_hand = player;
+        if player_missing > 0 {
+            player_hand = player_hand
+                .iter()
+                .chain(deck.deal(player_missing).unwrap().iter())
+                .collect::<Hand>();
+        }
+        // Evaluate the player's hand given the completed board
+        player_hand.extend(complete_board.iter());
+        let player_rank = poker_rank(&player_hand);
+
+        let opponent_ranks = opponents
+            .iter()
+            .map(|o| {
+                let mut opponent = *o;
+                let missing = 2 - opponent.len();
+                if missing > 0 {
+                    opponent = opponent
+                        .iter()
+                        .chain(deck.deal(missing).unwrap().iter())
+                        .collect::<Hand>();
+                }
+                opponent.extend(complete_board.iter());
+                poker_rank(&opponent)
+            })
+            .collect::<Vec<_>>();
+
+        // And record the player's share of the pot for the run
+        tally.record(player_rank, &opponent_ranks);
+    }
+
+    tally
+}
+
+/// Accumulates win/tie/loss counts across run-outs, both overall (for the
+/// pot-share `equity`) and per opponent, as `calculate` evaluates each run.
+struct Tally {
+    pots_won: f64,
+    wins: f64,
+    ties: f64,
+    losses: f64,
+    opponent_wins: Vec<f64>,
+    opponent_ties: Vec<f64>,
+    opponent_losses: Vec<f64>,
+    runs: usize,
+}
+
+impl Tally {
+    fn new(opponent_count: usize) -> Self {
+        Self {
+            pots_won: 0.0,
+            wins: 0.0,
+            ties: 0.0,
+            losses: 0.0,
+            opponent_wins: vec![0.0; opponent_count],
+            opponent_ties: vec![0.0; opponent_count],
+            opponent_losses: vec![0.0; opponent_count],
+            runs: 0,
+        }
+    }
+
+    /// Record the outcome of a single run-out: the player's rank against the
+    /// best opponent (for pot share) and against each opponent individually.
+    fn record(&mut self, player_rank: HandRank, opponent_ranks: &[HandRank]) {
+        let best_opponent = *opponent_ranks.iter().max().unwrap();
+        match player_rank.cmp(&best_opponent) {
+            Ordering::Greater => {
+                self.pots_won += 1.0;
+                self.wins += 1.0;
+            }
+            Ordering::Less => self.losses += 1.0,
+            Ordering::Equal => {
+                self.pots_won += 0.5;
+                self.ties += 1.0;
+            }
+        }
+
+        for (i, &rank) in opponent_ranks.iter().enumerate() {
+            match player_rank.cmp(&rank) {
+                Ordering::Greater => self.opponent_wins[i] += 1.0,
+                Ordering::Less => self.opponent_losses[i] += 1.0,
+                Ordering::Equal => self.opponent_ties[i] += 1.0,
+            }
+        }
+
+        self.runs += 1;
+    }
+
+    /// Fold another tally's counts into this one, used to combine the
+    /// per-thread results of a parallel Monte Carlo run.
+    fn merge(&mut self, other: Tally) {
+        self.pots_won += other.pots_won;
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+        self.runs += other.runs;
+
+        for i in 0..self.opponent_wins.len() {
+            self.opponent_wins[i] += other.opponent_wins[i];
+            self.opponent_ties[i] += other.opponent_ties[i];
+            self.opponent_losses[i] += other.opponent_losses[i];
+        }
+    }
+
+    fn pot_share(&self) -> f64 {
+        self.pots_won / self.runs as f64
+    }
+
+    fn win_rate(&self) -> f64 {
+        self.wins / self.runs as f64
+    }
+
+    fn tie_rate(&self) -> f64 {
+        self.ties / self.runs as f64
+    }
+
+    fn loss_rate(&self) -> f64 {
+        self.losses / self.runs as f64
+    }
+
+    fn opponent_equities(&self) -> Vec<OpponentEquity> {
+        (0..self.opponent_wins.len())
+            .map(|i| OpponentEquity {
+                wins: self.opponent_wins[i] / self.runs as f64,
+                ties: self.opponent_ties[i] / self.runs as f64,
+                losses: self.opponent_losses[i] / self.runs as f64,
+            })
+            .collect()
+    }
+}
+
+/// Lexicographic generator over the k-combinations of `0..n`, used to
+/// enumerate every possible run-out during exact equity calculation.
+struct Combinations {
+    indices: Vec<usize>,
+    n: usize,
+    first: bool,
+    done: bool,
 }
 
-fn parse_hand(val: &str, max: usize) -> Result<Hand, String> {
+impl Combinations {
+    fn new(n: usize, k: usize) -> Self {
+        Self {
+            indices: (0..k).collect(),
+            n,
+            first: true,
+            done: k > n,
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.first {
+            self.first = false;
+            return Some(self.indices.clone());
+        }
+
+        let k = self.indices.len();
+        let mut i = k;
+        loop {
+            if i == 0 {
+                self.done = true;
+                return None;
+            }
+            i -= 1;
+            if self.indices[i] != i + self.n - k {
+                break;
+            }
+        }
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+        Some(self.indices.clone())
+    }
+}
+
+/// Number of ways to choose `k` items from `n`, used to size an exact
+/// enumeration before running it.
+fn binomial(n: usize, k: usize) -> u128 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+    }
+    result
+}
+
+/// Format a single card in poker notation, e.g. `Card` for the ace of spades
+/// becomes `"As"`.
+pub(crate) fn format_card(card: &Card) -> String {
+    let rank_str = match card.rank() {
+        Rank::Ace => "A",
+        Rank::King => "K",
+        Rank::Queen => "Q",
+        Rank::Jack => "J",
+        Rank::Ten => "T",
+        Rank::Nine => "9",
+        Rank::Eight => "8",
+        Rank::Seven => "7",
+        Rank::Six => "6",
+        Rank::Five => "5",
+        Rank::Four => "4",
+        Rank::Three => "3",
+        Rank::Two => "2",
+    };
+
+    let suit_str = match card.suit() {
+        Suit::Hearts => "h",
+        Suit::Diamonds => "d",
+        Suit::Clubs => "c",
+        Suit::Spades => "s",
+    };
+
+    format!("{}{}", rank_str, suit_str)
+}
+
+pub(crate) fn parse_hand(val: &str, max: usize) -> Result<Hand, String> {
     let hand: Hand = match val.parse::<Hand>() {
         Ok(hand) => {
             if hand.len() > max {
@@ -262,3 +829,124 @@ fn parse_hand(val: &str, max: usize) -> Result<Hand, String> {
 
     Ok(hand)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combinations_enumerates_every_k_subset_in_order() {
+        let combos: Vec<Vec<usize>> = Combinations::new(4, 2).collect();
+        assert_eq!(
+            combos,
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3],
+            ]
+        );
+    }
+
+    #[test]
+    fn combinations_is_empty_when_k_exceeds_n() {
+        assert_eq!(Combinations::new(2, 3).count(), 0);
+    }
+
+    #[test]
+    fn binomial_matches_known_values() {
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(52, 5), 2_598_960);
+        assert_eq!(binomial(5, 0), 1);
+        assert_eq!(binomial(5, 6), 0);
+    }
+
+    #[test]
+    fn binomial_matches_combinations_count() {
+        assert_eq!(binomial(7, 3), Combinations::new(7, 3).count() as u128);
+    }
+
+    #[test]
+    fn tally_records_win_tie_and_loss_against_the_best_opponent() {
+        let player_rank = poker_rank(&"AhAsAdAcKh".parse::<Hand>().unwrap());
+        let better_rank = poker_rank(&"AsKsQsJsTs".parse::<Hand>().unwrap());
+        let worse_rank = poker_rank(&"2h3h4h5h7c".parse::<Hand>().unwrap());
+
+        let mut tally = Tally::new(2);
+        tally.record(player_rank, &[worse_rank, worse_rank]);
+        tally.record(player_rank, &[player_rank, worse_rank]);
+        tally.record(player_rank, &[better_rank, worse_rank]);
+
+        assert_eq!(tally.runs, 3);
+        assert_eq!(tally.pot_share(), (1.0 + 0.5) / 3.0);
+        assert_eq!(tally.win_rate(), 1.0 / 3.0);
+        assert_eq!(tally.tie_rate(), 1.0 / 3.0);
+        assert_eq!(tally.loss_rate(), 1.0 / 3.0);
+
+        let opponent_equities = tally.opponent_equities();
+        assert_eq!(opponent_equities[0].wins, 1.0 / 3.0);
+        assert_eq!(opponent_equities[0].ties, 1.0 / 3.0);
+        assert_eq!(opponent_equities[0].losses, 1.0 / 3.0);
+        assert_eq!(opponent_equities[1].wins, 1.0);
+    }
+
+    #[test]
+    fn tally_merge_sums_both_tallies() {
+        let player_rank = poker_rank(&"AsKsQsJsTs".parse::<Hand>().unwrap());
+        let worse_rank = poker_rank(&"2h3h4h5h7c".parse::<Hand>().unwrap());
+
+        let mut a = Tally::new(1);
+        a.record(player_rank, &[worse_rank]);
+        let mut b = Tally::new(1);
+        b.record(player_rank, &[worse_rank]);
+
+        a.merge(b);
+
+        assert_eq!(a.runs, 2);
+        assert_eq!(a.win_rate(), 1.0);
+    }
+
+    #[test]
+    fn monte_carlo_chunks_covers_every_sample_exactly_once() {
+        let chunks = monte_carlo_chunks(25_000, 42);
+        assert_eq!(
+            chunks.iter().map(|(samples, _)| samples).sum::<usize>(),
+            25_000
+        );
+        assert!(chunks
+            .iter()
+            .all(|(samples, _)| *samples <= MONTE_CARLO_CHUNK_SIZE));
+    }
+
+    #[test]
+    fn monte_carlo_chunks_partition_is_independent_of_thread_count() {
+        // The partition only depends on samples/seed -- it has no notion of
+        // a thread count to begin with, so the same inputs always produce
+        // the same chunk list no matter how many workers later consume it.
+        let a = monte_carlo_chunks(123_456, 7);
+        let b = monte_carlo_chunks(123_456, 7);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn monte_carlo_chunk_is_deterministic_for_a_fixed_seed() {
+        let player = "AsKs".parse::<Hand>().unwrap();
+        let board = "QsJsTs".parse::<Hand>().unwrap();
+        let opponent = "2h3h".parse::<Hand>().unwrap();
+        let available = CARDS
+            .iter()
+            .filter(|c| !player.contains(c) && !board.contains(c) && !opponent.contains(c))
+            .cloned()
+            .collect::<Vec<Card>>();
+
+        let run = || monte_carlo_chunk(50, 99, &available, player, board, 2, 0, &[opponent]);
+        let first = run();
+        let second = run();
+
+        assert_eq!(first.runs, second.runs);
+        assert_eq!(first.win_rate(), second.win_rate());
+        assert_eq!(first.tie_rate(), second.tie_rate());
+    }
+}
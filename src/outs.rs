@@ -0,0 +1,84 @@
+use crate::format_card;
+use crate::hand_category::HandCategory;
+use aya_poker::base::{Card, Hand, CARDS};
+use aya_poker::poker_rank;
+use ext_php_rs::prelude::*;
+
+#[php_class]
+#[php(name = "Poker\\Outs")]
+pub struct Outs {
+    /// @var int number of cards found
+    #[php(prop)]
+    pub count: usize,
+    /// @var string[] cards that improve the hero's hand, in poker notation
+    #[php(prop)]
+    pub cards: Vec<String>,
+}
+
+#[php_impl]
+impl Outs {
+    #[php(name = "__toString")]
+    pub fn stringable(&self) -> String {
+        format!("{} outs: {}", self.count, self.cards.join(", "))
+    }
+}
+
+/// Find every remaining card that improves `player`'s hand on the given
+/// partial board: a card beats every `opponent` it previously didn't, or,
+/// when no opponents are given, a card raises hero's hand category.
+pub(crate) fn find_outs(player: Hand, board: Hand, opponents: Vec<Hand>) -> Outs {
+    let all_opponent_cards = opponents.iter().flat_map(|o| o.iter()).collect::<Hand>();
+    let is_available =
+        |c: &Card| !player.contains(c) && !all_opponent_cards.contains(c) && !board.contains(c);
+
+    let mut current_hand = player;
+    current_hand.extend(board.iter());
+    let current_rank = poker_rank(&current_hand);
+
+    let current_opponent_rank = (!opponents.is_empty()).then(|| {
+        opponents
+            .iter()
+            .map(|o| {
+                let mut opponent = *o;
+                opponent.extend(board.iter());
+                poker_rank(&opponent)
+            })
+            .max()
+            .unwrap()
+    });
+
+    let mut cards = Vec::new();
+    for card in CARDS.iter().filter(|c| is_available(c)) {
+        let mut next_board = board;
+        next_board.extend([*card].iter());
+
+        let mut next_hand = player;
+        next_hand.extend(next_board.iter());
+        let next_rank = poker_rank(&next_hand);
+
+        let improves = match current_opponent_rank {
+            Some(before) => {
+                let after = opponents
+                    .iter()
+                    .map(|o| {
+                        let mut opponent = *o;
+                        opponent.extend(next_board.iter());
+                        poker_rank(&opponent)
+                    })
+                    .max()
+                    .unwrap();
+                current_rank <= before && next_rank > after
+            }
+            None => HandCategory::from_rank(next_rank) > HandCategory::from_rank(current_rank),
+        };
+
+        if improves {
+            cards.push(format_card(card));
+        }
+    }
+
+    Outs {
+        count: cards.len(),
+        cards,
+    }
+}